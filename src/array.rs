@@ -0,0 +1,145 @@
+use std::mem;
+use traits::{AsBits, TryFromBits};
+use BitsIter;
+
+/// A fixed-size array of `N` bits, stored without heap allocation.
+///
+/// Unlike the [`AsBits`]/[`FromBits`](crate::FromBits) family, which always goes
+/// through an integer type, `BitArray` owns its bits directly, so it is usable in
+/// `no_std` contexts where `Vec` is unavailable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitArray<const N: usize> {
+    bits: [bool; N],
+}
+
+impl<const N: usize> BitArray<N> {
+    /// Creates a `BitArray` with all bits cleared.
+    pub fn new() -> Self {
+        BitArray { bits: [false; N] }
+    }
+
+    /// Builds a `BitArray<N>` from an integer.
+    ///
+    /// # Panics
+    /// Panics if `T`'s bit width does not equal `N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let array: BitArray<8> = BitArray::from_int(5u8);
+    /// assert!(array.get(0));
+    /// assert!(!array.get(1));
+    /// ```
+    pub fn from_int<T>(value: T) -> Self
+    where
+        T: AsBits,
+        BitsIter<T>: ExactSizeIterator<Item = bool>,
+    {
+        let iter = value.iter_bits();
+        assert_eq!(iter.len(), N, "integer width does not match BitArray<N>");
+
+        let mut bits = [false; N];
+        for (i, bit) in iter.enumerate() {
+            bits[i] = bit;
+        }
+
+        BitArray { bits }
+    }
+
+    /// Converts this `BitArray<N>` back into an integer.
+    ///
+    /// # Panics
+    /// Panics if `T`'s bit width does not equal `N`. Without this check, a
+    /// narrower `N` would silently zero-extend into a wider `T`, since
+    /// [`TryFromBits::try_from_bits`] only rejects bit slices it cannot fit,
+    /// not ones that underfill the target.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let array: BitArray<8> = BitArray::from_int(5u8);
+    /// assert_eq!(5u8, array.to_int());
+    /// ```
+    pub fn to_int<T: TryFromBits>(&self) -> T {
+        assert_eq!(
+            mem::size_of::<T>() * 8,
+            N,
+            "integer width does not match BitArray<N>"
+        );
+        T::try_from_bits(&self.bits).expect("BitArray width does not match the target integer type")
+    }
+
+    /// Returns the bit at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let array: BitArray<8> = BitArray::from_int(5u8);
+    /// assert!(array.get(0));
+    /// assert!(!array.get(1));
+    /// ```
+    pub fn get(&self, index: usize) -> bool {
+        self.bits[index]
+    }
+
+    /// Sets the bit at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let mut array: BitArray<8> = BitArray::new();
+    /// array.set(0, true);
+    /// assert_eq!(1u8, array.to_int());
+    /// ```
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.bits[index] = value;
+    }
+
+    /// Returns the bit at `index`, without bounds checking.
+    ///
+    /// # Safety
+    /// `index` must be `< N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let array: BitArray<8> = BitArray::from_int(5u8);
+    /// unsafe {
+    ///     assert!(array.contains_unchecked(0));
+    ///     assert!(!array.contains_unchecked(1));
+    /// }
+    /// ```
+    pub unsafe fn contains_unchecked(&self, index: usize) -> bool {
+        *self.bits.get_unchecked(index)
+    }
+
+    /// Sets the bit at `index` to `true`, without bounds checking.
+    ///
+    /// # Safety
+    /// `index` must be `< N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use bitty::*;
+    /// let mut array: BitArray<8> = BitArray::new();
+    /// unsafe {
+    ///     array.insert_unchecked(0);
+    /// }
+    /// assert_eq!(1u8, array.to_int());
+    /// ```
+    pub unsafe fn insert_unchecked(&mut self, index: usize) {
+        *self.bits.get_unchecked_mut(index) = true;
+    }
+}
+
+impl<const N: usize> Default for BitArray<N> {
+    fn default() -> Self {
+        BitArray::new()
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// Errors produced by the fallible bit conversions, such as [`crate::TryFromBits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitsError {
+    /// `bits` contained more bits than the target integer type can hold.
+    TooManyBits { len: usize, capacity: usize },
+}
+
+impl fmt::Display for BitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitsError::TooManyBits { len, capacity } => write!(
+                f,
+                "too many bits: got {}, but the target type only holds {}",
+                len, capacity
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitsError {}
@@ -10,18 +10,21 @@
 //! ```
 //!
 //! # Use cases
-//! Extracting individual bits:
+//! Extracting individual bits (requires the default `std` feature; doctest is
+//! `ignore`d here because rustdoc cannot compile it conditionally on a feature
+//! flag, but it is exercised directly on [`AsBits::as_bits`]):
 //!
-//! ```rust
+//! ```rust,ignore
 //! # use bitty::*;
 //! let five_as_bits: Vec<bool> = 5u8.as_bits();
 //! // Expected: 1 0 1 0 0 0 0 0
 //! assert_eq!(five_as_bits, vec![true, false, true, false, false, false, false, false]);
 //! ```
 //!
-//! Putting bits back into an u8:
+//! Putting bits back into an u8 (also `std`-only, for the same reason; see
+//! [`FromBits::from_bits`] for the tested version):
 //!
-//! ```rust
+//! ```rust,ignore
 //! # use bitty::*;
 //! let five_from_bits: u8 = u8::from_bits(&5u8.as_bits());
 //! assert_eq!(5, five_from_bits);
@@ -36,15 +39,109 @@
 //! let one_from_bits: u64 = u64::from_bits(&vec![true]);
 //! assert_eq!(1, one_from_bits);
 //! ```
+//!
+//! Reducing bits to a single boolean or count:
+//!
+//! ```rust
+//! # use bitty::*;
+//! assert!(5u8.any());
+//! assert!(0xFFu8.all());
+//! assert!(!0xFFu16.all()); // 0xFF does not fill all 16 bits.
+//! assert!(7u8.parity()); // 0b0000_0111 has an odd number of set bits.
+//! assert_eq!(2, 5u8.count_ones());
+//! ```
+//!
+//! Iterating over bits without allocating:
+//!
+//! ```rust
+//! # use bitty::*;
+//! let msb_first: Vec<bool> = 5u8.iter_bits().rev().collect();
+//! assert_eq!(msb_first, vec![false, false, false, false, false, true, false, true]);
+//! ```
+//!
+//! Parsing an untrusted, variable-length bit stream without risking a panic:
+//!
+//! ```rust
+//! # use bitty::*;
+//! let too_many_bits = vec![true; 12];
+//! assert_eq!(
+//!     Err(BitsError::TooManyBits { len: 12, capacity: 8 }),
+//!     u8::try_from_bits(&too_many_bits),
+//! );
+//! ```
+//!
+//! Extracting bits MSB-first instead of the default LSB-first (`std`-only, `ignore`d
+//! for the same reason as above; see [`AsBits::as_bits_ordered`]):
+//!
+//! ```rust,ignore
+//! # use bitty::*;
+//! let msb_first: Vec<bool> = 5u8.as_bits_ordered(BitOrder::Msb0);
+//! assert_eq!(msb_first, vec![false, false, false, false, false, true, false, true]);
+//! ```
+//!
+//! Signed integers, `u128`/`i128` and `usize`/`isize` are supported too, sign bit included
+//! (`std`-only, `ignore`d for the same reason):
+//!
+//! ```rust,ignore
+//! # use bitty::*;
+//! assert_eq!(-1i8, i8::from_bits(&(-1i8).as_bits()));
+//! ```
+//!
+//! Storing bits in a fixed-size, `no_std`-friendly array instead of a `Vec`:
+//!
+//! ```rust
+//! # use bitty::*;
+//! let array: BitArray<8> = BitArray::from_int(5u8);
+//! assert!(array.get(0));
+//! assert!(!array.get(1));
+//! assert_eq!(5u8, array.to_int());
+//! ```
+//!
+//! ## `no_std`
+//! The `Vec`-returning APIs (`as_bits`, `as_bits_until`, `as_bits_ordered`, ...) are
+//! gated behind the default `std` feature. Disabling it (`default-features = false`)
+//! makes the crate `no_std`; [`BitArray`], `iter_bits`, `from_bits` and `try_from_bits`
+//! remain available either way.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Let `use std::...` keep working unchanged throughout the crate when the
+// `std` feature is disabled, instead of feature-gating every such import.
+#[cfg(not(feature = "std"))]
+extern crate core as std;
+
+pub use array::BitArray;
+pub use error::BitsError;
+pub use order::BitOrder;
 pub use traits::*;
 
+mod array;
+mod error;
 mod macros;
+mod order;
+
+/// A lazy, allocation-free iterator over the bits of an integer, yielded LSB-first.
+///
+/// Returned by [`AsBits::iter_bits`]. Walking it from the back (e.g. via
+/// [`DoubleEndedIterator::next_back`] or `.rev()`) visits bits MSB-first without
+/// having to reverse a collected vector.
+pub struct BitsIter<T> {
+    pub(crate) value: T,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+}
 
 pub mod traits {
+    use super::BitOrder;
+    use super::BitsError;
+    use super::BitsIter;
+
     pub trait AsBits {
         /// Extracts all bits as a boolean vector.
         ///
+        /// Requires the default `std` feature; use [`AsBits::iter_bits`] in `no_std`
+        /// contexts.
+        ///
         /// # Examples
         /// ```rust
         /// # use bitty::*;
@@ -52,10 +149,14 @@ pub mod traits {
         /// // Expected: 1 0 1 0 0 0 0 0
         /// assert_eq!(bits, vec![true, false, true, false, false, false, false, false]);
         /// ```
+        #[cfg(feature = "std")]
         fn as_bits(&self) -> Vec<bool>;
 
         /// Extracts bits until an index as a boolean vector.
         ///
+        /// Requires the default `std` feature; use [`AsBits::iter_bits`] in `no_std`
+        /// contexts.
+        ///
         /// # Arguments
         /// * `until` - Take bits until this index (exclusive)
         ///
@@ -69,10 +170,14 @@ pub mod traits {
         /// // Expected: 1 0 1 0
         /// assert_eq!(bits, vec![true, false, true, false]);
         /// ```
+        #[cfg(feature = "std")]
         fn as_bits_until(&self, until: usize) -> Vec<bool>;
 
         /// Extracts bits until an index as a boolean vector.
         ///
+        /// Requires the default `std` feature; use [`AsBits::iter_bits`] in `no_std`
+        /// contexts.
+        ///
         /// # Arguments
         /// * `until` - Take bits until this index (exclusive)
         ///
@@ -89,15 +194,51 @@ pub mod traits {
         ///     assert_eq!(bits, vec![true, false, true, false]);
         /// }
         /// ```
+        #[cfg(feature = "std")]
         unsafe fn as_bits_until_unchecked(&self, until: usize) -> Vec<bool>;
+
+        /// Returns a lazy iterator over the bits, LSB-first, without allocating.
+        ///
+        /// The iterator implements [`ExactSizeIterator`] and [`DoubleEndedIterator`],
+        /// so it can be walked MSB-first (e.g. via `.rev()`) without collecting into
+        /// a `Vec` first.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// let bits: Vec<bool> = 5u8.iter_bits().collect();
+        /// // Expected: 1 0 1 0 0 0 0 0
+        /// assert_eq!(bits, vec![true, false, true, false, false, false, false, false]);
+        /// ```
+        fn iter_bits(&self) -> BitsIter<Self>
+        where
+            Self: Sized;
+
+        /// Extracts all bits as a boolean vector, in the given [`BitOrder`].
+        ///
+        /// `as_bits_ordered(BitOrder::Lsb0)` is equivalent to [`AsBits::as_bits`].
+        ///
+        /// Requires the default `std` feature.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// let bits: Vec<bool> = 5u8.as_bits_ordered(BitOrder::Msb0);
+        /// // Expected: 0 0 0 0 0 1 0 1
+        /// assert_eq!(bits, vec![false, false, false, false, false, true, false, true]);
+        /// ```
+        #[cfg(feature = "std")]
+        fn as_bits_ordered(&self, order: BitOrder) -> Vec<bool>;
     }
 
     pub trait FromBits {
         /// Puts bits back into an integer type.
         ///
         /// # Arguments
-        /// * `bits` - A boolean slice containing all bits of the integer.
-        /// Missing bits default to 0.
+        /// * `bits` - A boolean slice containing all bits of the integer. Missing bits default to 0.
+        ///
+        /// This is the panicking convenience wrapper over [`TryFromBits::try_from_bits`];
+        /// use that instead when `bits` comes from an untrusted or variable-length source.
         ///
         /// # Panics
         /// This function panics if the length of `bits` is larger than the size of the integer type.
@@ -113,8 +254,7 @@ pub mod traits {
         /// Puts bits back into an integer type.
         ///
         /// # Arguments
-        /// * `bits` - A boolean slice containing all bits of the integer.
-        /// Missing bits default to 0.
+        /// * `bits` - A boolean slice containing all bits of the integer. Missing bits default to 0.
         ///
         /// # Safety
         /// The code might try to bit shift outside the size of the integer type, which is UB.
@@ -129,5 +269,119 @@ pub mod traits {
         /// }
         /// ```
         unsafe fn from_bits_unchecked(bits: &[bool]) -> Self;
+
+        /// Puts bits back into an integer type, reading them in the given [`BitOrder`].
+        ///
+        /// `from_bits_ordered(bits, BitOrder::Lsb0)` is equivalent to [`FromBits::from_bits`].
+        ///
+        /// # Panics
+        /// This function panics if the length of `bits` is larger than the size of the integer type.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// let msb_first: Vec<bool> = vec![false, false, false, false, false, true, false, true];
+        /// assert_eq!(5u8, u8::from_bits_ordered(&msb_first, BitOrder::Msb0));
+        /// ```
+        fn from_bits_ordered(bits: &[bool], order: BitOrder) -> Self;
+    }
+
+    pub trait TryFromBits: Sized {
+        /// Puts bits back into an integer type, failing instead of panicking on overflow.
+        ///
+        /// # Arguments
+        /// * `bits` - A boolean slice containing all bits of the integer. Missing bits default to 0.
+        ///
+        /// # Errors
+        /// Returns [`BitsError::TooManyBits`] if the length of `bits` is larger than the
+        /// size of the integer type.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// let bits: Vec<bool> = vec![true, true, true, true];
+        /// assert_eq!(Ok(15), u8::try_from_bits(&bits));
+        ///
+        /// let too_many_bits = vec![true; 12];
+        /// assert_eq!(
+        ///     Err(BitsError::TooManyBits { len: 12, capacity: 8 }),
+        ///     u8::try_from_bits(&too_many_bits),
+        /// );
+        /// ```
+        fn try_from_bits(bits: &[bool]) -> Result<Self, BitsError>;
+    }
+
+    pub trait BitReduce {
+        /// Returns `true` if any bit is set (logical OR of all bits).
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert!(5u8.any());
+        /// assert!(!0u8.any());
+        /// ```
+        fn any(&self) -> bool;
+
+        /// Returns `true` if every bit is set (logical AND over the full width of the type).
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert!(0xFFu8.all());
+        /// // 0xFF only fills the lower 8 bits of a u16.
+        /// assert!(!0xFFu16.all());
+        /// ```
+        fn all(&self) -> bool;
+
+        /// Returns `true` if the number of set bits is odd (logical XOR of all bits).
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert!(7u8.parity()); // 0b0000_0111 has 3 (odd) set bits.
+        /// assert!(!5u8.parity()); // 0b0000_0101 has 2 (even) set bits.
+        /// ```
+        fn parity(&self) -> bool;
+
+        /// Returns the number of set bits.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert_eq!(2, 5u8.count_ones());
+        /// ```
+        fn count_ones(&self) -> u32;
+
+        /// Returns `true` if any of the low `until` bits is set.
+        ///
+        /// # Arguments
+        /// * `until` - Only consider bits below this index (exclusive)
+        ///
+        /// # Panics
+        /// This function panics if `until` is larger than the size of the integer type.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert!(!0b0010_0000u8.any_until(4));
+        /// assert!(0b0000_1000u8.any_until(4));
+        /// ```
+        fn any_until(&self, until: usize) -> bool;
+
+        /// Returns `true` if all of the low `until` bits are set.
+        ///
+        /// # Arguments
+        /// * `until` - Only consider bits below this index (exclusive)
+        ///
+        /// # Panics
+        /// This function panics if `until` is larger than the size of the integer type.
+        ///
+        /// # Examples
+        /// ```rust
+        /// # use bitty::*;
+        /// assert!(0b0000_1111u8.all_until(4));
+        /// assert!(!0b0000_1101u8.all_until(4));
+        /// ```
+        fn all_until(&self, until: usize) -> bool;
     }
 }
@@ -1,41 +1,96 @@
+use std::mem;
 use traits::*;
+use BitOrder;
+use BitsError;
+use BitsIter;
 
 macro_rules! impl_as_bits {
     ($T:ty, $length:expr) => (
         impl AsBits for $T {
+            #[cfg(feature = "std")]
             fn as_bits(&self) -> Vec<bool> {
-                // This is safe because the length is defined at compile time.
-                unsafe {
-                    self.as_bits_until_unchecked($length)
-                }
+                self.iter_bits().collect()
             }
 
+            #[cfg(feature = "std")]
             fn as_bits_until(&self, until: usize) -> Vec<bool> {
                 assert!(until <= $length);
                 unsafe { self.as_bits_until_unchecked(until) }
             }
 
+            #[cfg(feature = "std")]
             unsafe fn as_bits_until_unchecked(&self, until: usize) -> Vec<bool> {
                 let mut bits = Vec::<bool>::with_capacity(until);
 
                 for i in 0..until {
-                    // Select bit at index i.
+                    // Select bit at index i and compare the masked value directly
+                    // against zero, rather than shifting it back down: for signed
+                    // types, shifting a lone sign bit back with `>>` sign-extends
+                    // and never equals `1`.
                     //
                     // Let i = 3, x = 0110
                     // Create mask:
                     //      1 << 3 = 0100
                     // Select:
                     //      x & mask
-                    //      0110 & 0100 = 0100
-                    // Move back:
-                    //      0100 >> 3 = 0001
-                    // Boolean value : true
-                    let bit = self & (1 << i);
-                    bits.push(bit >> i == 1);
+                    //      0110 & 0100 = 0100 (non-zero, so the bit is set)
+                    bits.push(self & ((1 as $T) << i) != 0);
                 }
 
                 bits
             }
+
+            fn iter_bits(&self) -> BitsIter<Self> {
+                BitsIter {
+                    value: *self,
+                    front: 0,
+                    back: $length,
+                }
+            }
+
+            #[cfg(feature = "std")]
+            fn as_bits_ordered(&self, order: BitOrder) -> Vec<bool> {
+                match order {
+                    BitOrder::Lsb0 => self.as_bits(),
+                    BitOrder::Msb0 => self.iter_bits().rev().collect(),
+                }
+            }
+        }
+
+        impl Iterator for BitsIter<$T> {
+            type Item = bool;
+
+            fn next(&mut self) -> Option<bool> {
+                if self.front >= self.back {
+                    return None;
+                }
+
+                let bit = self.value & (1 << self.front) != 0;
+                self.front += 1;
+                Some(bit)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.len();
+                (len, Some(len))
+            }
+        }
+
+        impl ExactSizeIterator for BitsIter<$T> {
+            fn len(&self) -> usize {
+                self.back - self.front
+            }
+        }
+
+        impl DoubleEndedIterator for BitsIter<$T> {
+            fn next_back(&mut self) -> Option<bool> {
+                if self.front >= self.back {
+                    return None;
+                }
+
+                self.back -= 1;
+                Some(self.value & (1 << self.back) != 0)
+            }
         }
     )
 }
@@ -44,10 +99,7 @@ macro_rules! impl_from_bits {
     ($T:ty, $length:expr) => (
         impl FromBits for $T {
             fn from_bits(bits: &[bool]) -> Self {
-                assert!(bits.len() <= $length);
-                unsafe {
-                    <$T>::from_bits_unchecked(bits)
-                }
+                <$T>::try_from_bits(bits).expect("too many bits for the target type")
             }
 
             unsafe fn from_bits_unchecked(bits: &[bool]) -> Self {
@@ -63,12 +115,96 @@ macro_rules! impl_from_bits {
                     //      x | mask
                     //      0000 | 0100 = 0100
                     if bit {
-                        val = val | (1 << i);
+                        val |= 1 << i;
                     }
                 }
 
                 val
             }
+
+            fn from_bits_ordered(bits: &[bool], order: BitOrder) -> Self {
+                match order {
+                    BitOrder::Lsb0 => Self::from_bits(bits),
+                    BitOrder::Msb0 => {
+                        // Reverse into a stack buffer (sized for the type's full
+                        // width) instead of a `Vec`, so this stays `no_std`-friendly.
+                        assert!(bits.len() <= $length);
+                        let mut reversed = [false; $length];
+                        let len = bits.len();
+                        for (i, &bit) in bits.iter().enumerate() {
+                            reversed[len - 1 - i] = bit;
+                        }
+                        Self::from_bits(&reversed[..len])
+                    }
+                }
+            }
+        }
+
+        impl TryFromBits for $T {
+            fn try_from_bits(bits: &[bool]) -> Result<Self, BitsError> {
+                if bits.len() > $length {
+                    return Err(BitsError::TooManyBits {
+                        len: bits.len(),
+                        capacity: $length,
+                    });
+                }
+
+                unsafe { Ok(<$T>::from_bits_unchecked(bits)) }
+            }
+        }
+    )
+}
+
+// Mask of the `until` lowest bits of a `$T`, e.g. `until == 3` gives `0b111`.
+//
+// Built with `wrapping_sub` rather than `-`: for signed types, `1 << until` with
+// `until == $length - 1` lands exactly on the sign bit (e.g. `1i8 << 7 ==
+// i8::MIN`), and subtracting 1 from that with checked arithmetic overflows.
+// Wrapping produces the same all-ones-below-`until` bit pattern either way.
+//
+// Shared by `any_until`/`all_until` below; a macro rather than a generic fn
+// since it needs to construct literal `0`/`1` values of the per-invocation `$T`.
+macro_rules! low_mask {
+    ($T:ty, $length:expr, $until:expr) => {
+        if $until == $length {
+            !(0 as $T)
+        } else {
+            ((1 as $T) << $until).wrapping_sub(1)
+        }
+    };
+}
+
+macro_rules! impl_bit_reduce {
+    ($T:ty, $length:expr) => (
+        impl BitReduce for $T {
+            fn any(&self) -> bool {
+                *self != 0
+            }
+
+            fn all(&self) -> bool {
+                // All-ones bit pattern for this type, regardless of signedness.
+                *self == !(0 as $T)
+            }
+
+            fn parity(&self) -> bool {
+                self.count_ones() % 2 == 1
+            }
+
+            fn count_ones(&self) -> u32 {
+                (*self).count_ones()
+            }
+
+            fn any_until(&self, until: usize) -> bool {
+                assert!(until <= $length);
+                let mask = low_mask!($T, $length, until);
+                *self & mask != 0
+            }
+
+            fn all_until(&self, until: usize) -> bool {
+                assert!(until <= $length);
+                let mask = low_mask!($T, $length, until);
+                *self & mask == mask
+            }
         }
     )
 }
@@ -77,8 +213,40 @@ impl_as_bits!(u8, 8);
 impl_as_bits!(u16, 16);
 impl_as_bits!(u32, 32);
 impl_as_bits!(u64, 64);
+impl_as_bits!(u128, 128);
+impl_as_bits!(usize, mem::size_of::<usize>() * 8);
+
+impl_as_bits!(i8, 8);
+impl_as_bits!(i16, 16);
+impl_as_bits!(i32, 32);
+impl_as_bits!(i64, 64);
+impl_as_bits!(i128, 128);
+impl_as_bits!(isize, mem::size_of::<isize>() * 8);
 
 impl_from_bits!(u8, 8);
 impl_from_bits!(u16, 16);
 impl_from_bits!(u32, 32);
 impl_from_bits!(u64, 64);
+impl_from_bits!(u128, 128);
+impl_from_bits!(usize, mem::size_of::<usize>() * 8);
+
+impl_from_bits!(i8, 8);
+impl_from_bits!(i16, 16);
+impl_from_bits!(i32, 32);
+impl_from_bits!(i64, 64);
+impl_from_bits!(i128, 128);
+impl_from_bits!(isize, mem::size_of::<isize>() * 8);
+
+impl_bit_reduce!(u8, 8);
+impl_bit_reduce!(u16, 16);
+impl_bit_reduce!(u32, 32);
+impl_bit_reduce!(u64, 64);
+impl_bit_reduce!(u128, 128);
+impl_bit_reduce!(usize, mem::size_of::<usize>() * 8);
+
+impl_bit_reduce!(i8, 8);
+impl_bit_reduce!(i16, 16);
+impl_bit_reduce!(i32, 32);
+impl_bit_reduce!(i64, 64);
+impl_bit_reduce!(i128, 128);
+impl_bit_reduce!(isize, mem::size_of::<isize>() * 8);
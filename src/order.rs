@@ -0,0 +1,12 @@
+/// The order in which bits are laid out relative to an integer's value.
+///
+/// `bitty`'s default methods (`as_bits`/`from_bits`) always use [`BitOrder::Lsb0`].
+/// Use the `_ordered` variants with [`BitOrder::Msb0`] to interoperate with wire
+/// or display formats that list the most significant bit first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Least significant bit first, e.g. `5u8` as `1 0 1 0 0 0 0 0`.
+    Lsb0,
+    /// Most significant bit first, e.g. `5u8` as `0 0 0 0 0 1 0 1`.
+    Msb0,
+}